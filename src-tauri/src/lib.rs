@@ -0,0 +1,306 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use tauri::{
+    menu::{Menu, MenuBuilder, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder},
+    tray::TrayIconBuilder,
+    Emitter, Manager, WindowEvent,
+};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+use serde::Serialize;
+
+mod login;
+mod notifications;
+
+use notifications::Scheduler;
+
+// Tracks whether the frontend has unsaved accounting state (a half-entered
+// invoice or journal entry). Registered from JS via the `set_dirty` command and
+// consulted when the user tries to close the window.
+static DIRTY: AtomicBool = AtomicBool::new(false);
+
+// Custom commands that can be called from JavaScript
+#[tauri::command]
+fn get_app_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+#[tauri::command]
+fn show_about_dialog(app: tauri::AppHandle) {
+    let version = env!("CARGO_PKG_VERSION");
+    let message = format!(
+        "SageFlow Accounting\n\nVersion: {}\n\nModern accounting software for Ethiopian businesses.\n\n© 2026 SageFlow",
+        version
+    );
+
+    app.dialog()
+        .message(message)
+        .title("About SageFlow")
+        .show(|_| {});
+}
+
+// Register pending (dirty) changes with the Rust side so window-close can warn
+// before losing unsaved entries.
+#[tauri::command]
+fn set_dirty(state: bool) {
+    DIRTY.store(state, Ordering::SeqCst);
+}
+
+// Summary of an available update returned to the frontend. `available` is false
+// when the app is already on the latest version, in which case the other fields
+// describe the current release.
+#[derive(Serialize)]
+struct UpdateInfo {
+    available: bool,
+    current_version: String,
+    version: String,
+    notes: Option<String>,
+}
+
+// Holds the update resolved by the most recent `check_for_update` so that
+// `install_update` can reuse it instead of hitting the release endpoint a
+// second time.
+#[derive(Default)]
+struct PendingUpdate(Mutex<Option<Update>>);
+
+// Check the configured release endpoint for a newer, signature-verified build.
+// The signature is validated by the updater plugin against the public key in
+// `tauri.conf.json`. `get_app_version` is the current-version source.
+#[tauri::command]
+async fn check_for_update(
+    app: tauri::AppHandle,
+    pending: tauri::State<'_, PendingUpdate>,
+) -> Result<UpdateInfo, String> {
+    let current_version = get_app_version();
+
+    match app.updater().map_err(|e| e.to_string())?.check().await {
+        Ok(Some(update)) => {
+            let info = UpdateInfo {
+                available: true,
+                current_version,
+                version: update.version.clone(),
+                notes: update.body.clone(),
+            };
+            // Stash the resolved update so `install_update` can reuse it.
+            *pending.0.lock().unwrap() = Some(update);
+            Ok(info)
+        }
+        Ok(None) => {
+            *pending.0.lock().unwrap() = None;
+            Ok(UpdateInfo {
+                available: false,
+                version: current_version.clone(),
+                current_version,
+                notes: None,
+            })
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// Download and install the pending update, emitting `updater://download-progress`
+// events (downloaded/total bytes) so the frontend can render a progress dialog.
+// The app must be restarted by the caller once this resolves.
+#[tauri::command]
+async fn install_update(
+    app: tauri::AppHandle,
+    pending: tauri::State<'_, PendingUpdate>,
+) -> Result<(), String> {
+    // Reuse the update resolved by `check_for_update` rather than re-hitting the
+    // release endpoint. The frontend always checks before installing; if no
+    // update was stashed there is nothing to install.
+    let update = pending
+        .0
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or_else(|| "no update available — call check_for_update first".to_string())?;
+
+    let mut downloaded: usize = 0;
+    update
+        .download_and_install(
+            |chunk_length, content_length| {
+                downloaded += chunk_length;
+                let _ = app.emit(
+                    "updater://download-progress",
+                    (downloaded, content_length),
+                );
+            },
+            || {
+                let _ = app.emit("updater://downloaded", ());
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Build the native application menu. Menu items emit `menu://…` events to the
+// webview so the accounting frontend can react to OS-native navigation.
+fn build_menu(app: &tauri::AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let file = SubmenuBuilder::new(app, "File")
+        .item(&MenuItemBuilder::with_id("new-invoice", "New Invoice").accelerator("CmdOrCtrl+N").build(app)?)
+        .item(&MenuItemBuilder::with_id("open-company", "Open Company").accelerator("CmdOrCtrl+O").build(app)?)
+        .separator()
+        .item(&MenuItemBuilder::with_id("export", "Export…").accelerator("CmdOrCtrl+E").build(app)?)
+        .separator()
+        .item(&PredefinedMenuItem::quit(app, None)?)
+        .build()?;
+
+    let edit = SubmenuBuilder::new(app, "Edit")
+        .undo()
+        .redo()
+        .separator()
+        .cut()
+        .copy()
+        .paste()
+        .select_all()
+        .build()?;
+
+    let reports = SubmenuBuilder::new(app, "Reports")
+        .item(&MenuItemBuilder::with_id("report-balance-sheet", "Balance Sheet").build(app)?)
+        .item(&MenuItemBuilder::with_id("report-profit-loss", "Profit & Loss").build(app)?)
+        .item(&MenuItemBuilder::with_id("report-vat", "VAT Return").build(app)?)
+        .build()?;
+
+    let help = SubmenuBuilder::new(app, "Help")
+        .item(&MenuItemBuilder::with_id("about", "About SageFlow").build(app)?)
+        .build()?;
+
+    MenuBuilder::new(app)
+        .items(&[&file, &edit, &reports, &help])
+        .build()
+}
+
+// Route a menu/tray item id to the matching `menu://…` webview event.
+fn handle_menu_event(app: &tauri::AppHandle, id: &str) {
+    match id {
+        "new-invoice" => {
+            let _ = app.emit("menu://new-invoice", ());
+        }
+        "open-company" => {
+            let _ = app.emit("menu://open-company", ());
+        }
+        "export" => {
+            let _ = app.emit("menu://export", ());
+        }
+        "report-balance-sheet" => {
+            let _ = app.emit("menu://report-balance-sheet", ());
+        }
+        "report-profit-loss" => {
+            let _ = app.emit("menu://report-profit-loss", ());
+        }
+        "report-vat" => {
+            let _ = app.emit("menu://report-vat", ());
+        }
+        "about" => show_about_dialog(app.clone()),
+        "show" => {
+            if let Some(window) = app.get_webview_window("main") {
+                window.show().unwrap();
+                window.set_focus().unwrap();
+            }
+        }
+        "hide" => {
+            if let Some(window) = app.get_webview_window("main") {
+                window.hide().unwrap();
+            }
+        }
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+// Spawn the native unlock window. The main window stays hidden until a correct
+// PIN emits `auth://unlocked`.
+#[tauri::command]
+fn open_login_window(app: tauri::AppHandle) -> Result<(), String> {
+    login::open(&app).map_err(|e| e.to_string())
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
+        .setup(|app| {
+            let handle = app.handle();
+
+            // Holds the update resolved by `check_for_update` for reuse by
+            // `install_update`.
+            app.manage(PendingUpdate::default());
+
+            // Start the reminder scheduler so notifications fire even when the
+            // window is hidden in the tray.
+            app.manage(Scheduler::start(handle.clone()));
+
+            // Native application menu.
+            let menu = build_menu(handle)?;
+            app.set_menu(menu)?;
+            app.on_menu_event(|app, event| handle_menu_event(app, event.id().as_ref()));
+
+            // System tray with Show / Hide / Quit.
+            let tray_menu = MenuBuilder::new(handle)
+                .item(&MenuItemBuilder::with_id("show", "Show").build(handle)?)
+                .item(&MenuItemBuilder::with_id("hide", "Hide").build(handle)?)
+                .separator()
+                .item(&MenuItemBuilder::with_id("quit", "Quit").build(handle)?)
+                .build()?;
+            TrayIconBuilder::new()
+                .menu(&tray_menu)
+                .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
+                .build(app)?;
+
+            // Hide the main window up front so it cannot be seen or interacted
+            // with before the PIN is entered, then present the login screen. The
+            // main window is revealed only once the PIN is accepted and
+            // `auth://unlocked` is emitted.
+            if let Some(window) = app.get_webview_window("main") {
+                window.hide()?;
+            }
+            login::open(handle)?;
+
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                // Nothing unsaved — let the close proceed normally.
+                if !DIRTY.load(Ordering::SeqCst) {
+                    return;
+                }
+
+                // Keep the window open until the user confirms.
+                api.prevent_close();
+
+                let window = window.clone();
+                window
+                    .dialog()
+                    .message("You have unsaved entries — close anyway?")
+                    .title("Unsaved entries")
+                    .buttons(MessageDialogButtons::OkCancel)
+                    .show(move |confirmed| {
+                        if confirmed {
+                            DIRTY.store(false, Ordering::SeqCst);
+                            window.close().unwrap();
+                        }
+                    });
+            }
+        })
+        .invoke_handler(tauri::generate_handler![
+            get_app_version,
+            show_about_dialog,
+            set_dirty,
+            check_for_update,
+            install_update,
+            open_login_window,
+            login::submit_pin,
+            notifications::notify,
+            notifications::schedule_reminder,
+            notifications::notification_clicked
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
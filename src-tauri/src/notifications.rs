@@ -0,0 +1,137 @@
+//! Native OS notifications for accounting reminders and background tasks.
+//!
+//! Notifications fire through Tauri's notification plugin so they reach the user
+//! even when the window is hidden in the tray. A Rust-side [`Scheduler`] fires
+//! time-based reminders (VAT filing due, overdue invoices) from a background
+//! thread.
+//!
+//! `tauri-plugin-notification` exposes no desktop click/action callback to Rust,
+//! so clicks are observed on the frontend via the plugin's JS `onAction` and
+//! routed back through the [`notification_clicked`] command, which brings the
+//! window forward and re-emits `notify://clicked` with the record id for the
+//! frontend router. As each notification is displayed, `notify://shown` is
+//! emitted with the record id so the frontend can correlate a later click back
+//! to the originating record.
+
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tauri::{Emitter, Manager};
+use tauri_plugin_notification::{NotificationExt, PermissionState};
+
+// A single queued reminder: fire `title`/`body` at `fire_at`, tagged with the
+// record `id` carried into the `notify://shown` payload.
+struct Reminder {
+    id: String,
+    title: String,
+    body: String,
+    fire_at: Instant,
+}
+
+// Handle to the background scheduler thread, stored as managed state.
+pub struct Scheduler {
+    tx: Sender<Reminder>,
+}
+
+impl Scheduler {
+    // Spawn the scheduler thread. It owns a clone of the app handle so it can
+    // raise notifications independently of the webview lifecycle.
+    pub fn start(app: tauri::AppHandle) -> Self {
+        let (tx, rx) = mpsc::channel::<Reminder>();
+
+        thread::spawn(move || {
+            let mut pending: Vec<Reminder> = Vec::new();
+            loop {
+                // Drain newly scheduled reminders, waking at least once a second
+                // to fire any that have come due.
+                match rx.recv_timeout(Duration::from_secs(1)) {
+                    Ok(reminder) => pending.push(reminder),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+
+                let now = Instant::now();
+                let (due, rest): (Vec<_>, Vec<_>) =
+                    pending.into_iter().partition(|r| r.fire_at <= now);
+                pending = rest;
+
+                for reminder in due {
+                    fire(&app, &reminder.id, &reminder.title, &reminder.body);
+                }
+            }
+        });
+
+        Self { tx }
+    }
+
+    // Queue a reminder to fire after `delay_secs`.
+    fn schedule(&self, id: String, title: String, body: String, delay_secs: u64) {
+        let _ = self.tx.send(Reminder {
+            id,
+            title,
+            body,
+            fire_at: Instant::now() + Duration::from_secs(delay_secs),
+        });
+    }
+}
+
+// Raise a single native notification, requesting permission on first use, and
+// announce the display on `notify://shown` so the frontend can associate the
+// record `id` with it. Clicks are handled frontend-side (see module docs).
+fn fire(app: &tauri::AppHandle, id: &str, title: &str, body: &str) {
+    let notifier = app.notification();
+
+    let granted = match notifier.permission_state() {
+        Ok(PermissionState::Granted) => true,
+        _ => matches!(
+            notifier.request_permission(),
+            Ok(PermissionState::Granted)
+        ),
+    };
+    if !granted {
+        return;
+    }
+
+    let _ = notifier.builder().title(title).body(body).show();
+    let _ = app.emit("notify://shown", id);
+}
+
+// Show an immediate native notification. `kind` tags the reminder category
+// (e.g. "reminder", "overdue-invoice", "task") for frontend routing; it is sent
+// as the `notify://shown` payload in place of a record id.
+#[tauri::command]
+pub fn notify(
+    app: tauri::AppHandle,
+    title: String,
+    body: String,
+    kind: String,
+) -> Result<(), String> {
+    fire(&app, &kind, &title, &body);
+    Ok(())
+}
+
+// Schedule a reminder to fire after `delay_secs`, even while minimized to tray.
+#[tauri::command]
+pub fn schedule_reminder(
+    scheduler: tauri::State<'_, Scheduler>,
+    id: String,
+    title: String,
+    body: String,
+    delay_secs: u64,
+) {
+    scheduler.schedule(id, title, body, delay_secs);
+}
+
+// Bring the main window forward and route to the record identified by `id`.
+// Invoked by the frontend from the notification plugin's click/action event,
+// since the plugin surfaces clicks to the webview rather than to Rust; re-emits
+// `notify://clicked` so the frontend router can navigate to the record.
+#[tauri::command]
+pub fn notification_clicked(app: tauri::AppHandle, id: String) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let _ = app.emit("notify://clicked", id);
+}